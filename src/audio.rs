@@ -0,0 +1,236 @@
+use anyhow::{Context, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use std::collections::VecDeque;
+use std::io::BufReader;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+const SAMPLE_RATE: u32 = 48_000;
+const CHANNELS: u16 = 2;
+// ~2 seconds of stereo audio; bounds memory use and caps how far decoding can
+// run ahead of playback.
+const RING_CAPACITY: usize = SAMPLE_RATE as usize * CHANNELS as usize * 2;
+
+struct RingBuffer {
+    samples: Mutex<VecDeque<f32>>,
+    not_full: Condvar,
+    eof: AtomicBool,
+}
+
+/// Outcome of a non-blocking pop, distinguishing a transient underrun (keep
+/// playing silence, more samples are still coming) from true end-of-stream.
+enum PopResult {
+    Sample(f32),
+    Underrun,
+    Eof,
+}
+
+impl RingBuffer {
+    fn new() -> Self {
+        RingBuffer {
+            samples: Mutex::new(VecDeque::with_capacity(RING_CAPACITY)),
+            not_full: Condvar::new(),
+            eof: AtomicBool::new(false),
+        }
+    }
+
+    /// Blocks while the ring is full instead of dropping unplayed samples,
+    /// so a decode thread that runs faster than real time (ffmpeg decoding
+    /// is bounded by CPU/disk, not playback rate) paces itself to the
+    /// consumer rather than racing ahead and discarding most of the track.
+    fn push(&self, sample: f32) {
+        let mut buf = self.samples.lock().unwrap();
+        while buf.len() >= RING_CAPACITY {
+            buf = self.not_full.wait(buf).unwrap();
+        }
+        buf.push_back(sample);
+    }
+
+    /// Marks the stream as fully decoded, so a subsequent empty `try_pop`
+    /// reports `Eof` instead of `Underrun`.
+    fn mark_eof(&self) {
+        self.eof.store(true, Ordering::Relaxed);
+    }
+
+    /// Non-blocking pop for the real-time cpal callback: an empty ring that
+    /// hasn't hit EOF yet is a transient underrun (decode thread briefly
+    /// outpaced by playback), not end-of-stream, so it returns `Underrun`
+    /// instead of waiting on the audio thread.
+    fn try_pop(&self) -> PopResult {
+        let mut buf = self.samples.lock().unwrap();
+        if let Some(s) = buf.pop_front() {
+            self.not_full.notify_one();
+            return PopResult::Sample(s);
+        }
+        if self.eof.load(Ordering::Relaxed) {
+            PopResult::Eof
+        } else {
+            PopResult::Underrun
+        }
+    }
+}
+
+/// A running audio pipeline: an ffmpeg process decoding `input`'s audio
+/// track into the ring buffer, and a cpal output stream draining it.
+///
+/// Keep this alive for as long as audio should play; dropping it stops the
+/// output stream but does not kill or reap the ffmpeg child, so callers
+/// should track `ffmpeg_pid()` alongside their other child processes for
+/// shutdown and call `wait()` after killing it to avoid leaving a zombie.
+pub struct AudioHandle {
+    child: Child,
+    stream: cpal::Stream,
+    muted: Arc<AtomicBool>,
+    level: Arc<AtomicU32>,
+    drained: Arc<AtomicBool>,
+}
+
+impl AudioHandle {
+    pub fn ffmpeg_pid(&self) -> u32 {
+        self.child.id()
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted.load(Ordering::Relaxed)
+    }
+
+    pub fn set_muted(&self, muted: bool) {
+        self.muted.store(muted, Ordering::Relaxed);
+    }
+
+    /// Halt the output stream, e.g. to follow a pause of the presentation
+    /// clock. Playback resumes from wherever the ring buffer was left.
+    pub fn pause(&self) -> Result<()> {
+        self.stream.pause().context("Failed to pause audio stream")
+    }
+
+    pub fn resume(&self) -> Result<()> {
+        self.stream.play().context("Failed to resume audio stream")
+    }
+
+    /// Peak amplitude (0.0-1.0) of the most recently played audio chunk,
+    /// for driving a level meter in audio-only mode.
+    pub fn level(&self) -> f32 {
+        f32::from_bits(self.level.load(Ordering::Relaxed))
+    }
+
+    /// Whether decoding has finished *and* every decoded sample has actually
+    /// been played, as opposed to just the (much faster) decode process
+    /// having exited.
+    pub fn finished(&self) -> bool {
+        self.drained.load(Ordering::Relaxed)
+    }
+
+    /// Reap the decoding ffmpeg child. Call after killing it (e.g. on seek
+    /// or resize) so it doesn't linger as a zombie.
+    pub fn wait(&mut self) {
+        let _ = self.child.wait();
+    }
+}
+
+fn spawn_ffmpeg_audio(input: &str, start_offset_secs: f32) -> Result<Child> {
+    let mut cmd = Command::new("ffmpeg");
+    cmd.arg("-hide_banner").arg("-loglevel").arg("error").arg("-nostdin");
+
+    if start_offset_secs > 0.0 {
+        cmd.arg("-ss").arg(start_offset_secs.to_string());
+    }
+
+    cmd.arg("-i")
+        .arg(input)
+        .arg("-vn")
+        .arg("-f")
+        .arg("s16le")
+        .arg("-acodec")
+        .arg("pcm_s16le")
+        .arg("-ar")
+        .arg(SAMPLE_RATE.to_string())
+        .arg("-ac")
+        .arg(CHANNELS.to_string())
+        .arg("pipe:1")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit());
+
+    cmd.spawn()
+        .context("Failed to spawn ffmpeg for audio decoding. Is ffmpeg installed?")
+}
+
+/// Decode `input`'s audio track with ffmpeg, starting `start_offset_secs`
+/// into the file, and play it through the default output device via cpal,
+/// giving us a single audio clock instead of a separate, unsynced player
+/// process.
+pub fn spawn_audio(input: &str, start_offset_secs: f32) -> Result<AudioHandle> {
+    let mut child = spawn_ffmpeg_audio(input, start_offset_secs)?;
+    let stdout = child
+        .stdout
+        .take()
+        .context("ffmpeg audio stdout not captured")?;
+
+    let ring = Arc::new(RingBuffer::new());
+    let muted = Arc::new(AtomicBool::new(false));
+    let level = Arc::new(AtomicU32::new(0.0f32.to_bits()));
+    let drained = Arc::new(AtomicBool::new(false));
+
+    let decode_ring = ring.clone();
+    thread::spawn(move || {
+        let mut reader = BufReader::new(stdout);
+        while let Ok(sample) = reader.read_i16::<LittleEndian>() {
+            decode_ring.push(sample as f32 / i16::MAX as f32);
+        }
+        decode_ring.mark_eof();
+    });
+
+    let host = cpal::default_host();
+    let device = host
+        .default_output_device()
+        .context("No audio output device available")?;
+    let config = cpal::StreamConfig {
+        channels: CHANNELS,
+        sample_rate: cpal::SampleRate(SAMPLE_RATE),
+        buffer_size: cpal::BufferSize::Default,
+    };
+
+    let callback_muted = muted.clone();
+    let callback_level = level.clone();
+    let callback_drained = drained.clone();
+    let stream = device
+        .build_output_stream(
+            &config,
+            move |data: &mut [f32], _| {
+                let muted = callback_muted.load(Ordering::Relaxed);
+                let mut peak = 0.0f32;
+                for sample in data.iter_mut() {
+                    match ring.try_pop() {
+                        PopResult::Sample(s) => {
+                            peak = peak.max(s.abs());
+                            *sample = if muted { 0.0 } else { s };
+                        }
+                        PopResult::Underrun => {
+                            *sample = 0.0;
+                        }
+                        PopResult::Eof => {
+                            callback_drained.store(true, Ordering::Relaxed);
+                            *sample = 0.0;
+                        }
+                    }
+                }
+                callback_level.store(peak.to_bits(), Ordering::Relaxed);
+            },
+            |err| eprintln!("Audio stream error: {err}"),
+            None,
+        )
+        .context("Failed to open cpal output stream")?;
+
+    stream.play().context("Failed to start audio stream")?;
+
+    Ok(AudioHandle {
+        child,
+        stream,
+        muted,
+        level,
+        drained,
+    })
+}
@@ -0,0 +1,72 @@
+use anyhow::{Context, Result};
+use crossterm::event::{self, Event, KeyCode};
+use crossterm::terminal;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+/// A playback command produced by the raw-mode keyboard reader thread.
+pub enum Command {
+    TogglePause,
+    SeekBack,
+    SeekForward,
+    ToggleMute,
+    /// The terminal was resized; callers should re-query the new size via
+    /// `terminal_size()` rather than carrying it on this variant, since the
+    /// event and the handler that reacts to it can be separated in time.
+    Resized,
+}
+
+/// Restores the terminal's cooked mode on drop, so a panic or early return
+/// doesn't leave the user's shell in raw mode.
+pub struct RawModeGuard;
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Put the terminal into raw mode and spawn a thread that turns keystrokes
+/// and resize events into `Command`s delivered over the returned channel:
+/// space to pause/resume, left/right arrows to seek, `m` to toggle mute,
+/// and a `Resized` command whenever the window changes size.
+pub fn spawn_input_thread() -> Result<(RawModeGuard, Receiver<Command>)> {
+    terminal::enable_raw_mode().context("Failed to enable terminal raw mode")?;
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || loop {
+        match event::poll(Duration::from_millis(100)) {
+            Ok(true) => {
+                let cmd = match event::read() {
+                    Ok(Event::Key(key)) => match key.code {
+                        KeyCode::Char(' ') => Some(Command::TogglePause),
+                        KeyCode::Left => Some(Command::SeekBack),
+                        KeyCode::Right => Some(Command::SeekForward),
+                        KeyCode::Char('m') => Some(Command::ToggleMute),
+                        _ => None,
+                    },
+                    Ok(Event::Resize(..)) => Some(Command::Resized),
+                    _ => None,
+                };
+                if let Some(cmd) = cmd {
+                    if tx.send(cmd).is_err() {
+                        break;
+                    }
+                }
+            }
+            Ok(false) => {}
+            Err(_) => break,
+        }
+    });
+
+    Ok((RawModeGuard, rx))
+}
+
+/// Current terminal size as (columns, rows), falling back to a conservative
+/// default when it can't be queried (e.g. output isn't a tty).
+pub fn terminal_size() -> (u32, u32) {
+    terminal::size()
+        .map(|(cols, rows)| (cols as u32, rows as u32))
+        .unwrap_or((80, 24))
+}
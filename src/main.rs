@@ -1,11 +1,18 @@
+mod audio;
+mod controls;
+
 use anyhow::{bail, Context, Result};
 use clap::Parser;
+use controls::Command as InputCommand;
+use rayon::prelude::*;
 use std::io::{self, Read, Write};
 use std::process::{Child, Command, Stdio};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
+const SEEK_STEP_SECS: f32 = 5.0;
+
 #[derive(Parser, Debug)]
 #[command(author, version, about = "Play videos in the terminal (ASCII) with optional sound", long_about = None)]
 struct Args {
@@ -14,40 +21,96 @@ struct Args {
     #[arg(long, default_value_t = 24)]
     fps: u32,
 
-    #[arg(long, default_value_t = 80)]
-    width: u32,
+    /// Terminal columns to render into. Defaults to the terminal's current
+    /// width; the video reflows on resize only when both --width and
+    /// --height are omitted.
+    #[arg(long)]
+    width: Option<u32>,
 
-    #[arg(long, default_value_t = 70)]
-    height: i32,
+    /// Scale target height in source pixels. When --width is also omitted,
+    /// defaults to whatever fills the terminal's current height while
+    /// preserving the source aspect ratio; with --width given, it follows
+    /// from that width and the source aspect ratio.
+    #[arg(long)]
+    height: Option<u32>,
 
     #[arg(long = "no-sound", default_value_t = false)]
     no_sound: bool,
     
     #[arg(long = "no-color", default_value_t = false)]
     no_color: bool,
+
+    /// Use the old renderer, which draws each pixel as two side-by-side
+    /// character cells, instead of the default half-block mode, which packs
+    /// two vertical pixels per cell.
+    #[arg(long = "full-block", default_value_t = false)]
+    full_block: bool,
+
+    /// Override input-kind detection when probing is ambiguous.
+    #[arg(long, value_enum)]
+    mode: Option<Mode>,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    Video,
+    Image,
+    Audio,
 }
 
-fn find_program(names: &[&str]) -> Option<String> {
-    for &n in names {
-        if which::which(n).is_ok() {
-            return Some(n.to_string());
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "bmp", "gif", "webp", "tiff", "tif"];
+const AUDIO_EXTENSIONS: &[&str] = &["mp3", "wav", "flac", "ogg", "m4a", "aac", "wma", "opus"];
+
+/// Which streams ffprobe reports for the input: (has_video, has_audio).
+/// Defaults to "assume both" when ffprobe is unavailable or fails, so
+/// detection falls back to treating the input as a normal video.
+fn probe_stream_kinds(path: &str) -> (bool, bool) {
+    if which::which("ffprobe").is_err() {
+        return (true, true);
+    }
+
+    let out = Command::new("ffprobe")
+        .arg("-v")
+        .arg("error")
+        .arg("-show_entries")
+        .arg("stream=codec_type")
+        .arg("-of")
+        .arg("csv=p=0")
+        .arg(path)
+        .output();
+
+    match out {
+        Ok(o) if o.status.success() => {
+            let s = String::from_utf8_lossy(&o.stdout);
+            let has_video = s.lines().any(|l| l.trim() == "video");
+            let has_audio = s.lines().any(|l| l.trim() == "audio");
+            (has_video, has_audio)
         }
+        _ => (true, true),
     }
-    None
 }
 
-fn spawn_audio_player(input: &str) -> Result<Child> {
-    if let Some(prog) = find_program(&["afplay"]) {
-        let child = Command::new(prog)
-            .arg(input)
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .spawn()
-            .context("Failed to spawn afplay for audio playback")?;
-        return Ok(child);
+/// Guess whether `input` is a video, a still image, or an audio-only file:
+/// extension first (cheap and usually unambiguous for images/audio), then
+/// falling back to which stream kinds ffprobe reports.
+fn detect_mode(input: &str) -> Mode {
+    let ext = std::path::Path::new(input)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+
+    if IMAGE_EXTENSIONS.contains(&ext.as_str()) {
+        return Mode::Image;
+    }
+    if AUDIO_EXTENSIONS.contains(&ext.as_str()) {
+        return Mode::Audio;
     }
 
-    bail!("afplay not found. Install afplay (macOS) or modify the code to use a different audio player.");
+    match probe_stream_kinds(input) {
+        (false, true) => Mode::Audio,
+        _ => Mode::Video,
+    }
 }
 
 fn probe_video_size(path: &str) -> Option<(u32, u32)> {
@@ -83,14 +146,49 @@ fn probe_video_size(path: &str) -> Option<(u32, u32)> {
     None
 }
 
-fn spawn_ffmpeg_raw(args: &Args, target_w: u32, target_h: u32) -> Result<Child> {
+/// Pick a render width/height (in source pixels) that fills the current
+/// terminal window while preserving the source aspect ratio, accounting for
+/// how many character cells each mode spends per source pixel: half-block
+/// mode packs 2 source rows into 1 character row, while the full-block color
+/// renderer widens each pixel into 2 character columns to compensate for the
+/// terminal font's roughly 1:2 width:height cell aspect. The ASCII renderer
+/// spends 1 column and 1 row per pixel and gets no cell-aspect correction.
+fn fit_to_terminal(
+    term_cols: u32,
+    term_rows: u32,
+    src_dims: Option<(u32, u32)>,
+    use_half_block: bool,
+    no_color: bool,
+) -> (u32, u32) {
+    let rows_per_char = if use_half_block { 2 } else { 1 };
+    let cols_per_pixel = if !no_color && !use_half_block { 2 } else { 1 };
+
+    let avail_w = (term_cols / cols_per_pixel).max(1);
+    let avail_h = (term_rows * rows_per_char).max(1);
+
+    match src_dims {
+        Some((src_w, src_h)) if src_w > 0 && src_h > 0 => {
+            let scale = (avail_w as f32 / src_w as f32).min(avail_h as f32 / src_h as f32);
+            (
+                ((src_w as f32 * scale) as u32).max(1),
+                ((src_h as f32 * scale) as u32).max(1),
+            )
+        }
+        _ => (avail_w, avail_h),
+    }
+}
+
+fn spawn_ffmpeg_raw(args: &Args, target_w: u32, target_h: u32, start_offset_secs: f32) -> Result<Child> {
     let vf = format!("fps={},scale={}:{}", args.fps, target_w, target_h);
 
-    let child = Command::new("ffmpeg")
-        .arg("-hide_banner")
-        .arg("-loglevel")
-        .arg("error")
-        .arg("-nostdin")
+    let mut cmd = Command::new("ffmpeg");
+    cmd.arg("-hide_banner").arg("-loglevel").arg("error").arg("-nostdin");
+
+    if start_offset_secs > 0.0 {
+        cmd.arg("-ss").arg(start_offset_secs.to_string());
+    }
+
+    let child = cmd
         .arg("-i")
         .arg(&args.input)
         .arg("-an")
@@ -109,51 +207,267 @@ fn spawn_ffmpeg_raw(args: &Args, target_w: u32, target_h: u32) -> Result<Child>
     Ok(child)
 }
 
-fn render_ascii_frame(buf: &[u8], w: u32, h: u32) -> String {
+fn kill_pid(pid: u32) {
+    let _ = Command::new("kill").arg("-9").arg(pid.to_string()).spawn();
+}
+
+fn render_ascii_row(buf: &[u8], w: usize, y: usize) -> String {
     const CHARS: &[u8] = b"@%#*+=-:. ";
-    let mut out = String::with_capacity((w as usize + 1) * h as usize);
-    for y in 0..h as usize {
-        for x in 0..w as usize {
-            let idx = (y * w as usize + x) * 3;
-            if idx + 2 >= buf.len() {
-                out.push(' ');
-                continue;
-            }
-            let r = buf[idx] as f32;
-            let g = buf[idx + 1] as f32;
-            let b = buf[idx + 2] as f32;
-            let lum = 0.2126 * r + 0.7152 * g + 0.0722 * b;
-            let t = (lum / 255.0) * ((CHARS.len() - 1) as f32);
-            let ch = CHARS[(CHARS.len() - 1 - t as usize).min(CHARS.len() - 1)];
-            out.push(ch as char);
+    let mut row = String::with_capacity(w);
+    for x in 0..w {
+        let idx = (y * w + x) * 3;
+        if idx + 2 >= buf.len() {
+            row.push(' ');
+            continue;
         }
-        if y != (h as usize - 1) {
-            out.push('\n');
+        let r = buf[idx] as f32;
+        let g = buf[idx + 1] as f32;
+        let b = buf[idx + 2] as f32;
+        let lum = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        let t = (lum / 255.0) * ((CHARS.len() - 1) as f32);
+        let ch = CHARS[(CHARS.len() - 1 - t as usize).min(CHARS.len() - 1)];
+        row.push(ch as char);
+    }
+    row
+}
+
+fn render_ascii_frame(buf: &[u8], w: u32, h: u32) -> String {
+    let w = w as usize;
+    let h = h as usize;
+    (0..h)
+        .into_par_iter()
+        .map(|y| render_ascii_row(buf, w, y))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn render_color_row(buf: &[u8], w: usize, y: usize) -> String {
+    let mut row = String::with_capacity(w * 8 + 4);
+    for x in 0..w {
+        let idx = (y * w + x) * 3;
+        if idx + 2 >= buf.len() {
+            row.push(' ');
+            continue;
         }
+        let r = buf[idx];
+        let g = buf[idx + 1];
+        let b = buf[idx + 2];
+        row.push_str(&format!("\x1b[48;2;{};{};{}m  ", r, g, b));
     }
-    out
+    row.push_str("\x1b[0m");
+    row
 }
 
 fn render_color_frame(buf: &[u8], w: u32, h: u32) -> String {
-    let mut out = String::with_capacity((w as usize * 8 + 1) * h as usize);
-    for y in 0..h as usize {
-        for x in 0..w as usize {
-            let idx = (y * w as usize + x) * 3;
-            if idx + 2 >= buf.len() {
-                out.push(' ');
-                continue;
+    let w = w as usize;
+    let h = h as usize;
+    (0..h)
+        .into_par_iter()
+        .map(|y| render_color_row(buf, w, y))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn half_block_pixel(buf: &[u8], w: usize, x: usize, y: usize) -> (u8, u8, u8) {
+    let idx = (y * w + x) * 3;
+    if idx + 2 >= buf.len() {
+        (0, 0, 0)
+    } else {
+        (buf[idx], buf[idx + 1], buf[idx + 2])
+    }
+}
+
+fn render_half_block_row(buf: &[u8], w: usize, h: usize, y: usize) -> String {
+    let mut row = String::with_capacity(w * 20 + 4);
+    for x in 0..w {
+        let (tr, tg, tb) = half_block_pixel(buf, w, x, y);
+        if y + 1 < h {
+            let (br, bg, bb) = half_block_pixel(buf, w, x, y + 1);
+            row.push_str(&format!(
+                "\x1b[38;2;{tr};{tg};{tb}m\x1b[48;2;{br};{bg};{bb}m\u{2580}"
+            ));
+        } else {
+            row.push_str(&format!("\x1b[48;2;{tr};{tg};{tb}m "));
+        }
+    }
+    row.push_str("\x1b[0m");
+    row
+}
+
+/// Render two source rows per terminal row using the upper-half-block glyph
+/// (`▀`), with the top pixel as foreground and the bottom pixel as
+/// background. This doubles the vertical resolution we get out of a
+/// character cell versus `render_color_frame`'s one-pixel-per-cell approach.
+fn render_half_block_frame(buf: &[u8], w: u32, h: u32) -> String {
+    let w = w as usize;
+    let h = h as usize;
+    (0..h)
+        .into_par_iter()
+        .step_by(2)
+        .map(|y| render_half_block_row(buf, w, h, y))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// (Re)compute render_w/target_h for the current terminal size and source
+/// aspect ratio, honoring any explicit `--width`/`--height` override.
+fn resolve_dimensions(args: &Args, use_half_block: bool, src_dims: Option<(u32, u32)>) -> (u32, u32) {
+    let (term_cols, term_rows) = controls::terminal_size();
+
+    if args.width.is_none() && args.height.is_none() {
+        return fit_to_terminal(term_cols, term_rows, src_dims, use_half_block, args.no_color);
+    }
+
+    let width = args.width.unwrap_or(term_cols);
+    let render_w = if args.no_color || use_half_block {
+        width
+    } else {
+        (width.max(1) / 2).max(1)
+    };
+
+    let target_h = match args.height {
+        Some(h) => h,
+        None => match src_dims {
+            Some((src_w, src_h)) => {
+                let mut h = (src_h as f32 * render_w as f32 / src_w as f32) as u32;
+                // Outside half-block mode (ASCII and full-block color),
+                // rows are scaled by this fudge factor. Half-block mode packs
+                // two source rows into each (roughly 1:2) character cell, so
+                // each source pixel is about square and needs no correction.
+                if !use_half_block {
+                    h = (h as f32 * 0.55).max(1.0) as u32;
+                }
+                h.max(1)
+            }
+            None => (render_w as f32 * 9.0 / 16.0) as u32,
+        },
+    };
+
+    (render_w, target_h)
+}
+
+/// Spawn ffmpeg (and, unless disabled, the audio pipeline) at the given
+/// scale and start offset, replacing `child_pids` with the new processes.
+/// Used both for the initial pipeline and to restart it on seek or resize.
+fn spawn_pipeline(
+    args: &Args,
+    render_w: u32,
+    target_h: u32,
+    start_offset_secs: f32,
+    child_pids: &Arc<Mutex<Vec<u32>>>,
+) -> Result<(Child, io::BufReader<std::process::ChildStdout>, Option<audio::AudioHandle>)> {
+    let mut ff = spawn_ffmpeg_raw(args, render_w, target_h, start_offset_secs)?;
+    let reader = io::BufReader::new(ff.stdout.take().context("ffmpeg stdout not captured")?);
+
+    let audio_handle = if !args.no_sound {
+        match audio::spawn_audio(&args.input, start_offset_secs) {
+            Ok(handle) => Some(handle),
+            Err(e) => {
+                eprintln!("Warning: couldn't start audio: {e}");
+                None
             }
-            let r = buf[idx];
-            let g = buf[idx + 1];
-            let b = buf[idx + 2];
-            out.push_str(&format!("\x1b[48;2;{};{};{}m  ", r, g, b));
         }
-        out.push_str("\x1b[0m");
-        if y != (h as usize - 1) {
-            out.push('\n');
+    } else {
+        None
+    };
+
+    if let Ok(mut lock) = child_pids.lock() {
+        lock.clear();
+        lock.push(ff.id());
+        if let Some(audio) = &audio_handle {
+            lock.push(audio.ffmpeg_pid());
         }
     }
-    out
+
+    Ok((ff, reader, audio_handle))
+}
+
+/// Decode a single frame with ffmpeg, render it once, and exit; there's no
+/// presentation clock or audio to run for a still image.
+fn run_image(args: &Args) -> Result<()> {
+    let use_half_block = !args.no_color && !args.full_block;
+    let src_dims = probe_video_size(&args.input);
+    let (render_w, target_h) = resolve_dimensions(args, use_half_block, src_dims);
+
+    let vf = format!("scale={}:{}", render_w, target_h);
+    let mut ff = Command::new("ffmpeg")
+        .arg("-hide_banner")
+        .arg("-loglevel")
+        .arg("error")
+        .arg("-nostdin")
+        .arg("-i")
+        .arg(&args.input)
+        .arg("-frames:v")
+        .arg("1")
+        .arg("-vf")
+        .arg(&vf)
+        .arg("-f")
+        .arg("rawvideo")
+        .arg("-pix_fmt")
+        .arg("rgb24")
+        .arg("pipe:1")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .context("Failed to spawn ffmpeg for image decoding. Is ffmpeg installed?")?;
+
+    let stdout = ff.stdout.take().context("ffmpeg stdout not captured")?;
+    let mut buf = vec![0u8; (render_w as usize) * (target_h as usize) * 3];
+    io::BufReader::new(stdout)
+        .read_exact(&mut buf)
+        .context("Failed to read decoded image frame")?;
+
+    let out = if args.no_color {
+        render_ascii_frame(&buf, render_w, target_h)
+    } else if use_half_block {
+        render_half_block_frame(&buf, render_w, target_h)
+    } else {
+        render_color_frame(&buf, render_w, target_h)
+    };
+
+    print!("\x1b[2J\x1b[H{}\n", out);
+    io::stdout().flush().ok();
+
+    let _ = ff.wait();
+    Ok(())
+}
+
+/// Skip the video pipe entirely: just run the audio subsystem and show
+/// elapsed time with a simple peak level meter until the track ends.
+fn run_audio_only(args: &Args) -> Result<()> {
+    if args.no_sound {
+        bail!("--no-sound was passed for an audio-only input; nothing to play.");
+    }
+
+    println!("Playing {} (audio only)", args.input);
+
+    let handle = audio::spawn_audio(&args.input, 0.0)?;
+    let pid = handle.ffmpeg_pid();
+
+    ctrlc::set_handler(move || {
+        eprintln!("Stopping playback...");
+        kill_pid(pid);
+        std::process::exit(0);
+    })
+    .context("Failed to set Ctrl-C handler")?;
+
+    const METER_WIDTH: usize = 30;
+    let start = Instant::now();
+
+    print!("\x1b[?25l");
+    while !handle.finished() {
+        let elapsed = start.elapsed().as_secs();
+        let filled = ((handle.level() * METER_WIDTH as f32) as usize).min(METER_WIDTH);
+        let meter = "#".repeat(filled) + &" ".repeat(METER_WIDTH - filled);
+
+        print!("\r{:02}:{:02} [{meter}]", elapsed / 60, elapsed % 60);
+        io::stdout().flush().ok();
+
+        thread::sleep(Duration::from_millis(100));
+    }
+
+    print!("\x1b[?25h\n");
+    Ok(())
 }
 
 fn main() -> Result<()> {
@@ -163,89 +477,169 @@ fn main() -> Result<()> {
         bail!("ffmpeg not found. Install ffmpeg and ensure it is on PATH.");
     }
 
-    println!(
-        "Playing {}  (fps={} width={} height={} sound={})",
-        args.input, args.fps, args.width, args.height, !args.no_sound
-    );
+    let mode = args.mode.unwrap_or_else(|| detect_mode(&args.input));
+    match mode {
+        Mode::Image => return run_image(&args),
+        Mode::Audio => return run_audio_only(&args),
+        Mode::Video => {}
+    }
 
-    let render_w = if args.no_color { args.width } else { (args.width.max(1) / 2).max(1) };
+    let use_half_block = !args.no_color && !args.full_block;
+    let src_dims = probe_video_size(&args.input);
+    let auto_fit = args.width.is_none() && args.height.is_none();
 
-    let target_h = if args.height > 0 {
-        args.height as u32
-    } else {
-        if let Some((src_w, src_h)) = probe_video_size(&args.input) {
-            let mut h = (src_h as f32 * render_w as f32 / src_w as f32) as u32;
+    let (mut render_w, mut target_h) = resolve_dimensions(&args, use_half_block, src_dims);
 
-            h = (h as f32 * 0.55).max(1.0) as u32;
-            h
-        } else {
-            (render_w as f32 * 9.0 / 16.0) as u32
-        }
-    };
+    println!(
+        "Playing {}  (fps={} width={} height={} sound={})",
+        args.input, args.fps, render_w, target_h, !args.no_sound
+    );
 
     let child_pids: Arc<Mutex<Vec<u32>>> = Arc::new(Mutex::new(vec![]));
 
-    if !args.no_sound {
-        let input_clone = args.input.clone();
-        let cp = child_pids.clone();
-        thread::spawn(move || {
-            match spawn_audio_player(&input_clone) {
-                Ok(mut ch) => {
-                    let pid = ch.id();
-                    if let Ok(mut lock) = cp.lock() {
-                        lock.push(pid);
-                    }
-                    let _ = ch.wait();
-                }
-                Err(e) => {
-                    eprintln!("Warning: couldn't start audio: {e}");
-                }
-            }
-        });
-    }
-
-    let mut ff = spawn_ffmpeg_raw(&args, render_w, target_h)?;
+    let (mut ff, mut reader, mut audio_handle) =
+        spawn_pipeline(&args, render_w, target_h, 0.0, &child_pids)?;
 
-    if let Ok(mut lock) = child_pids.lock() {
-        lock.push(ff.id());
-    }
     let cp = child_pids.clone();
     ctrlc::set_handler(move || {
         eprintln!("Stopping playback...");
         if let Ok(lock) = cp.lock() {
-            for pid in lock.iter() {
-                let _ = std::process::Command::new("kill")
-                    .arg("-9")
-                    .arg(pid.to_string())
-                    .spawn();
+            for &pid in lock.iter() {
+                kill_pid(pid);
             }
         }
         std::process::exit(0);
     })
     .context("Failed to set Ctrl-C handler")?;
 
-    let stdout = ff.stdout.take().context("ffmpeg stdout not captured")?;
-    let mut reader = io::BufReader::new(stdout);
-
-    let frame_size = (render_w as usize) * (target_h as usize) * 3;
-
     print!("\x1b[2J\x1b[H\x1b[?25l");
     io::stdout().flush().ok();
 
     let frame_duration = Duration::from_secs_f32(1.0 / args.fps as f32);
 
+    let mut frame_size = (render_w as usize) * (target_h as usize) * 3;
     let mut buf = vec![0u8; frame_size];
 
-    loop {
-        let start = Instant::now();
+    let (_raw_guard, input_rx) = controls::spawn_input_thread()?;
+
+    // Presentation clock: frame `n`'s target time is a fixed offset from
+    // `playback_start`, not "whenever the previous frame finished". This
+    // keeps long playbacks locked to real time instead of drifting whenever
+    // decode/render stalls, and gives us a single clock to reconcile with
+    // once audio samples can drive it too. `base_offset_secs` is how far
+    // into the source frame 0 of the current ffmpeg pipe starts, so seeking
+    // can reset the clock relative to a reopened pipe.
+    let mut playback_start = Instant::now();
+    let mut base_offset_secs: f32 = 0.0;
+    let mut n: u32 = 0;
+    let mut paused = false;
+    let mut pause_started = Instant::now();
+
+    'playback: loop {
+        let mut seek_delta: Option<f32> = None;
+        let mut resized = false;
+        while let Ok(cmd) = input_rx.try_recv() {
+            match cmd {
+                InputCommand::TogglePause => {
+                    paused = !paused;
+                    if paused {
+                        pause_started = Instant::now();
+                    } else {
+                        playback_start += pause_started.elapsed();
+                    }
+                    if let Some(audio) = &audio_handle {
+                        let _ = if paused { audio.pause() } else { audio.resume() };
+                    }
+                }
+                InputCommand::ToggleMute => {
+                    if let Some(audio) = &audio_handle {
+                        audio.set_muted(!audio.is_muted());
+                    }
+                }
+                InputCommand::SeekBack => seek_delta = Some(-SEEK_STEP_SECS),
+                InputCommand::SeekForward => seek_delta = Some(SEEK_STEP_SECS),
+                // Dimensions pinned by explicit --width/--height don't reflow.
+                InputCommand::Resized if auto_fit => resized = true,
+                InputCommand::Resized => {}
+            }
+        }
+
+        if paused {
+            thread::sleep(Duration::from_millis(50));
+            continue;
+        }
+
+        if resized {
+            let elapsed_secs = base_offset_secs + n as f32 / args.fps as f32;
+            (render_w, target_h) = resolve_dimensions(&args, use_half_block, src_dims);
+
+            kill_pid(ff.id());
+            let _ = ff.wait();
+            if let Some(mut audio) = audio_handle.take() {
+                kill_pid(audio.ffmpeg_pid());
+                audio.wait();
+            }
+
+            (ff, reader, audio_handle) =
+                spawn_pipeline(&args, render_w, target_h, elapsed_secs, &child_pids)?;
+
+            frame_size = (render_w as usize) * (target_h as usize) * 3;
+            buf = vec![0u8; frame_size];
+            base_offset_secs = elapsed_secs;
+            n = 0;
+            playback_start = Instant::now();
+            print!("\x1b[2J");
+            continue;
+        }
+
+        if let Some(delta) = seek_delta {
+            let elapsed_secs = n as f32 / args.fps as f32;
+            let target_offset = (base_offset_secs + elapsed_secs + delta).max(0.0);
+
+            kill_pid(ff.id());
+            let _ = ff.wait();
+            if let Some(mut audio) = audio_handle.take() {
+                kill_pid(audio.ffmpeg_pid());
+                audio.wait();
+            }
+
+            (ff, reader, audio_handle) =
+                spawn_pipeline(&args, render_w, target_h, target_offset, &child_pids)?;
+
+            base_offset_secs = target_offset;
+            n = 0;
+            playback_start = Instant::now();
+            print!("\x1b[2J");
+            continue;
+        }
+
+        let mut target = playback_start + frame_duration * n;
 
         if let Err(e) = reader.read_exact(&mut buf) {
             eprintln!("Finished reading frames or error: {e}");
             break;
         }
 
+        // We're more than a frame behind: drop the backlog without
+        // rendering it until we reach the frame nearest to now.
+        while Instant::now().saturating_duration_since(target) > frame_duration {
+            n += 1;
+            target = playback_start + frame_duration * n;
+            if let Err(e) = reader.read_exact(&mut buf) {
+                eprintln!("Finished reading frames or error: {e}");
+                break 'playback;
+            }
+        }
+
+        let now = Instant::now();
+        if now < target {
+            thread::sleep(target - now);
+        }
+
         let out = if args.no_color {
-            render_ascii_frame(&buf, args.width, target_h)
+            render_ascii_frame(&buf, render_w, target_h)
+        } else if use_half_block {
+            render_half_block_frame(&buf, render_w, target_h)
         } else {
             render_color_frame(&buf, render_w, target_h)
         };
@@ -254,17 +648,14 @@ fn main() -> Result<()> {
         print!("{}", out);
         io::stdout().flush().ok();
 
-        let elapsed = start.elapsed();
-        if elapsed < frame_duration {
-            thread::sleep(frame_duration - elapsed);
-        }
+        n += 1;
     }
 
     print!("\x1b[?25h\n");
 
     if let Ok(lock) = child_pids.lock() {
-        for pid in lock.iter() {
-            let _ = Command::new("kill").arg("-9").arg(pid.to_string()).spawn();
+        for &pid in lock.iter() {
+            kill_pid(pid);
         }
     }
 